@@ -78,12 +78,15 @@ impl ToolHandler for CollabHandler {
 mod spawn {
     use super::*;
     use crate::agent::AgentRole;
+    use crate::models_manager::manager::RefreshStrategy;
+    use codex_protocol::openai_models::ModelPreset;
     use std::sync::Arc;
 
     #[derive(Debug, Deserialize)]
     struct SpawnAgentArgs {
         message: String,
         agent_type: Option<AgentRole>,
+        model: Option<String>,
     }
 
     #[derive(Debug, Serialize)]
@@ -105,6 +108,10 @@ mod spawn {
                 "Empty message can't be sent to an agent".to_string(),
             ));
         }
+        let model_preset = match args.model.as_deref() {
+            Some(model) => resolve_model_preset(session.as_ref(), turn.as_ref(), model).await?,
+            None => None,
+        };
         session
             .send_event(
                 &turn,
@@ -121,6 +128,21 @@ mod spawn {
         agent_role
             .apply_to_config(&mut config)
             .map_err(FunctionCallError::RespondToModel)?;
+        // An explicit model wins over both the parent's model and the role default.
+        if let Some(model) = args.model {
+            // Keep the inherited effort only when the override model supports it.
+            if let Some(preset) = model_preset.as_ref()
+                && config.model_reasoning_effort.is_some_and(|effort| {
+                    !preset
+                        .supported_reasoning_efforts
+                        .iter()
+                        .any(|supported| supported.effort == effort)
+                })
+            {
+                config.model_reasoning_effort = Some(preset.default_reasoning_effort);
+            }
+            config.model = Some(model);
+        }
 
         let result = session
             .services
@@ -163,6 +185,40 @@ mod spawn {
             content_items: None,
         })
     }
+
+    /// Look up the preset for a requested model override.
+    /// Only OpenAI models are known to the models manager, so other providers skip the check
+    /// and get `None`. The parent's own model is always accepted, even when it has no preset.
+    async fn resolve_model_preset(
+        session: &Session,
+        turn: &TurnContext,
+        model: &str,
+    ) -> Result<Option<ModelPreset>, FunctionCallError> {
+        if !turn.client.provider().is_openai() {
+            return Ok(None);
+        }
+        let presets = session
+            .services
+            .models_manager
+            .list_models(turn.client.config().as_ref(), RefreshStrategy::Offline)
+            .await;
+        if let Some(preset) = presets.iter().find(|preset| preset.model == model) {
+            return Ok(Some(preset.clone()));
+        }
+        if model == turn.client.get_model() {
+            return Ok(None);
+        }
+        // Hidden presets are still accepted but not advertised.
+        let available = presets
+            .iter()
+            .filter(|preset| preset.show_in_picker)
+            .map(|preset| preset.model.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(FunctionCallError::RespondToModel(format!(
+            "unknown model {model}; available models: {available}"
+        )))
+    }
 }
 
 mod send_input {
@@ -596,16 +652,29 @@ fn build_agent_spawn_config(
 mod tests {
     use super::*;
     use crate::CodexAuth;
+    use crate::ModelClient;
     use crate::ThreadManager;
+    use crate::agent::AgentRole;
     use crate::built_in_model_providers;
     use crate::codex::make_session_and_context;
+    use crate::codex::make_session_and_context_with_rx;
     use crate::config::types::ShellEnvironmentPolicy;
     use crate::function_tool::FunctionCallError;
+    use crate::model_provider_info::ModelProviderInfo;
+    use crate::model_provider_info::OLLAMA_OSS_PROVIDER_ID;
+    use crate::models_manager::manager::RefreshStrategy;
     use crate::protocol::AskForApproval;
+    use crate::protocol::EventMsg;
     use crate::protocol::Op;
     use crate::protocol::SandboxPolicy;
     use crate::turn_diff_tracker::TurnDiffTracker;
     use codex_protocol::ThreadId;
+    use codex_protocol::openai_models::ModelInfo;
+    use codex_protocol::openai_models::ReasoningEffort;
+    use core_test_support::responses::ev_completed;
+    use core_test_support::responses::ev_response_created;
+    use core_test_support::responses::mount_sse_once;
+    use core_test_support::responses::sse;
     use pretty_assertions::assert_eq;
     use serde::Deserialize;
     use serde_json::json;
@@ -615,6 +684,7 @@ mod tests {
     use std::time::Duration;
     use tokio::sync::Mutex;
     use tokio::time::timeout;
+    use wiremock::MockServer;
 
     fn invocation(
         session: Arc<crate::codex::Session>,
@@ -645,6 +715,26 @@ mod tests {
         )
     }
 
+    fn replace_turn_client(
+        turn: &mut TurnContext,
+        conversation_id: ThreadId,
+        model_info: ModelInfo,
+        provider: ModelProviderInfo,
+        effort: Option<ReasoningEffort>,
+    ) {
+        turn.client = ModelClient::new(
+            turn.client.config(),
+            turn.client.get_auth_manager(),
+            model_info,
+            turn.client.get_otel_manager(),
+            provider,
+            effort,
+            turn.client.get_reasoning_summary(),
+            conversation_id,
+            turn.client.get_session_source(),
+        );
+    }
+
     #[tokio::test]
     async fn handler_rejects_non_function_payloads() {
         let (session, turn) = make_session_and_context().await;
@@ -705,6 +795,175 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn spawn_agent_rejects_unknown_model() {
+        let (session, turn, rx) = make_session_and_context_with_rx().await;
+        let available = session
+            .services
+            .models_manager
+            .list_models(turn.client.config().as_ref(), RefreshStrategy::Offline)
+            .await
+            .into_iter()
+            .filter(|preset| preset.show_in_picker)
+            .map(|preset| preset.model)
+            .collect::<Vec<_>>();
+        assert!(!available.is_empty());
+        let invocation = invocation(
+            session,
+            turn,
+            "spawn_agent",
+            function_payload(json!({"message": "hello", "model": "not-a-model"})),
+        );
+        let Err(err) = CollabHandler.handle(invocation).await else {
+            panic!("unknown model should be rejected");
+        };
+        assert_eq!(
+            err,
+            FunctionCallError::RespondToModel(format!(
+                "unknown model not-a-model; available models: {}",
+                available.join(", ")
+            ))
+        );
+        while let Ok(event) = rx.try_recv() {
+            assert!(
+                !matches!(event.msg, EventMsg::CollabAgentSpawnBegin(_)),
+                "spawn should not begin for an unknown model"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn spawn_agent_accepts_parent_model_without_preset() {
+        let (session, mut turn) = make_session_and_context().await;
+        let mut model_info = turn.client.get_model_info();
+        model_info.slug = "custom-parent-model".to_string();
+        let provider = built_in_model_providers()["openai"].clone();
+        let effort = turn.client.get_reasoning_effort();
+        replace_turn_client(
+            &mut turn,
+            session.conversation_id,
+            model_info,
+            provider,
+            effort,
+        );
+        let invocation = invocation(
+            Arc::new(session),
+            Arc::new(turn),
+            "spawn_agent",
+            function_payload(json!({"message": "hello", "model": "custom-parent-model"})),
+        );
+        let Err(err) = CollabHandler.handle(invocation).await else {
+            panic!("spawn should fail without a manager");
+        };
+        assert_eq!(
+            err,
+            FunctionCallError::RespondToModel("collab manager unavailable".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn spawn_agent_skips_model_check_for_non_openai_provider() {
+        let (session, mut turn) = make_session_and_context().await;
+        let provider = built_in_model_providers()[OLLAMA_OSS_PROVIDER_ID].clone();
+        let effort = turn.client.get_reasoning_effort();
+        let model_info = turn.client.get_model_info();
+        replace_turn_client(
+            &mut turn,
+            session.conversation_id,
+            model_info,
+            provider,
+            effort,
+        );
+        let invocation = invocation(
+            Arc::new(session),
+            Arc::new(turn),
+            "spawn_agent",
+            function_payload(json!({"message": "hello", "model": "llama3"})),
+        );
+        let Err(err) = CollabHandler.handle(invocation).await else {
+            panic!("spawn should fail without a manager");
+        };
+        assert_eq!(
+            err,
+            FunctionCallError::RespondToModel("collab manager unavailable".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn spawn_agent_model_override_wins_over_role_and_parent() {
+        let (mut session, mut turn) = make_session_and_context().await;
+        let manager = thread_manager();
+        session.services.agent_control = manager.agent_control();
+        let server = MockServer::start().await;
+        let response_mock = mount_sse_once(
+            &server,
+            sse(vec![ev_response_created("resp-1"), ev_completed("resp-1")]),
+        )
+        .await;
+        let mut provider = built_in_model_providers()["openai"].clone();
+        provider.base_url = Some(format!("{}/v1", server.uri()));
+        // gpt-5.1-codex-mini does not support xhigh, so the effort should fall back.
+        let model_info = turn.client.get_model_info();
+        replace_turn_client(
+            &mut turn,
+            session.conversation_id,
+            model_info,
+            provider,
+            Some(ReasoningEffort::XHigh),
+        );
+        let override_model = "gpt-5.1-codex-mini";
+        assert_ne!(turn.client.get_model(), override_model);
+        assert_ne!(AgentRole::Worker.profile().model, Some(override_model));
+
+        let invocation = invocation(
+            Arc::new(session),
+            Arc::new(turn),
+            "spawn_agent",
+            function_payload(json!({
+                "message": "hello",
+                "agent_type": "worker",
+                "model": override_model
+            })),
+        );
+        let output = CollabHandler
+            .handle(invocation)
+            .await
+            .expect("spawn_agent should succeed");
+        let ToolOutput::Function { content, .. } = output else {
+            panic!("expected function output");
+        };
+        let result: serde_json::Value =
+            serde_json::from_str(&content).expect("spawn_agent result should be json");
+        let agent_id = agent_id(
+            result["agent_id"]
+                .as_str()
+                .expect("agent_id should be a string"),
+        )
+        .expect("agent_id should be valid");
+
+        let request = timeout(Duration::from_secs(10), async {
+            loop {
+                if let Some(request) = response_mock.last_request() {
+                    break request;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("sub-agent should send a model request");
+        let body = request.body_json();
+        assert_eq!(body["model"], json!(override_model));
+        assert_eq!(body["reasoning"]["effort"], json!("medium"));
+
+        let _ = manager
+            .get_thread(agent_id)
+            .await
+            .expect("spawned thread should exist")
+            .submit(Op::Shutdown {})
+            .await
+            .expect("shutdown should submit");
+    }
+
     #[tokio::test]
     async fn spawn_agent_errors_when_manager_dropped() {
         let (session, turn) = make_session_and_context().await;